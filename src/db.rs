@@ -9,13 +9,52 @@ struct Player {
     role: String,
 }
 
-pub async fn create_game(pool: &SqlitePool, game: &GameInfo) -> sqlx::Result<()> {
+pub async fn ensure_dataset(pool: &SqlitePool, name: &str) -> sqlx::Result<()> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+    sqlx::query!(
+        "INSERT INTO datasets (name, last_import_at) VALUES (?, ?)
+         ON CONFLICT(name) DO UPDATE SET last_import_at = excluded.last_import_at",
+        name,
+        now
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn list_datasets(pool: &SqlitePool) -> sqlx::Result<Vec<types::Dataset>> {
+    let rows = sqlx::query!(
+        "SELECT d.name as name, d.last_import_at as last_import_at, COUNT(g.id) as game_count
+         FROM datasets d LEFT JOIN games g ON g.dataset = d.name
+         GROUP BY d.name, d.last_import_at"
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| types::Dataset {
+            name: row.name,
+            last_import_at: row.last_import_at,
+            game_count: row.game_count as i64,
+        })
+        .collect())
+}
+
+pub async fn create_game(
+    pool: &SqlitePool,
+    game: &GameInfo,
+    dataset: Option<&str>,
+) -> sqlx::Result<()> {
     let mut txn = pool.begin().await?;
     let game_id = Uuid::new_v4().to_string();
     sqlx::query!(
-        "insert into games (id, winner) VALUES (?, ?)",
+        "insert into games (id, winner, dataset) VALUES (?, ?, ?)",
         game_id,
-        game.result.winner
+        game.result.winner,
+        dataset
     )
     .execute(&mut txn)
     .await?;
@@ -62,11 +101,29 @@ pub async fn create_game(pool: &SqlitePool, game: &GameInfo) -> sqlx::Result<()>
     Ok(())
 }
 
-pub async fn find_game(pool: &SqlitePool, game_id: &str) -> sqlx::Result<GameInfo> {
-    let raw_winner = sqlx::query!("SELECT winner FROM games WHERE id = ?", game_id)
-        .fetch_one(pool)
-        .await?
-        .winner;
+pub async fn find_game(
+    pool: &SqlitePool,
+    game_id: &str,
+    dataset: Option<&str>,
+) -> sqlx::Result<GameInfo> {
+    let raw_winner = match dataset {
+        Some(dataset) => {
+            sqlx::query!(
+                "SELECT winner FROM games WHERE id = ? AND dataset = ?",
+                game_id,
+                dataset
+            )
+            .fetch_one(pool)
+            .await?
+            .winner
+        }
+        None => {
+            sqlx::query!("SELECT winner FROM games WHERE id = ?", game_id)
+                .fetch_one(pool)
+                .await?
+                .winner
+        }
+    };
     let mut player_records = sqlx::query_as!(
         Player,
         "SELECT name, role FROM player_roles WHERE game_id = ?",
@@ -103,28 +160,18 @@ pub async fn find_game(pool: &SqlitePool, game_id: &str) -> sqlx::Result<GameInf
         }
 
         quests.push(types::Quest {
-            status: types::QuestStatus::Success,
+            status: types::QuestStatus::try_from(quest.status.as_str())?,
             fails: quest.fails,
             participants: participant_names,
         });
     }
 
-    let num_failures = quests
-        .iter()
-        .filter(|q| q.status == types::QuestStatus::Fail)
-        .count();
-
     let winner = if raw_winner == Some("good".into()) {
         types::Alignment::Good
     } else {
         types::Alignment::Evil
     };
-
-    let victory_type = if num_failures < 3 && winner == types::Alignment::Evil {
-        types::VictoryType::Assassination
-    } else {
-        types::VictoryType::Quest
-    };
+    let victory_type = types::infer_victory_type(winner, &quests);
 
     Ok(GameInfo {
         players,
@@ -136,11 +183,21 @@ pub async fn find_game(pool: &SqlitePool, game_id: &str) -> sqlx::Result<GameInf
     })
 }
 
-pub async fn load_all_games(pool: &SqlitePool) -> sqlx::Result<Vec<GameInfo>> {
+pub async fn load_all_games(
+    pool: &SqlitePool,
+    dataset: Option<&str>,
+) -> sqlx::Result<Vec<GameInfo>> {
     let mut games = Vec::new();
-    let records = sqlx::query!("SELECT id FROM games").fetch_all(pool).await?;
+    let records = match dataset {
+        Some(dataset) => {
+            sqlx::query!("SELECT id FROM games WHERE dataset = ?", dataset)
+                .fetch_all(pool)
+                .await?
+        }
+        None => sqlx::query!("SELECT id FROM games").fetch_all(pool).await?,
+    };
     for record in records {
-        games.push(find_game(pool, record.id.unwrap().as_str()).await?);
+        games.push(find_game(pool, record.id.unwrap().as_str(), dataset).await?);
     }
     Ok(games)
 }
@@ -4,7 +4,7 @@ pub mod types;
 use std::env;
 use std::path::PathBuf;
 
-use db::{create_game, find_game, load_all_games};
+use db::{create_game, ensure_dataset, find_game, list_datasets, load_all_games};
 use sqlx::sqlite::SqlitePool;
 use structopt::StructOpt;
 
@@ -19,11 +19,45 @@ enum Command {
     Import {
         #[structopt(parse(from_os_str))]
         file: PathBuf,
+        #[structopt(long)]
+        dataset: Option<String>,
     },
     Load {
         id: String,
+        #[structopt(long)]
+        dataset: Option<String>,
+    },
+    Standings {
+        #[structopt(long)]
+        dataset: Option<String>,
+    },
+    Ratings {
+        #[structopt(long)]
+        dataset: Option<String>,
+    },
+    Predict {
+        #[structopt(long, use_delimiter = true)]
+        good: Vec<String>,
+        #[structopt(long, use_delimiter = true)]
+        evil: Vec<String>,
+        #[structopt(long)]
+        dataset: Option<String>,
+    },
+    Datasets,
+    RoleStats {
+        #[structopt(long)]
+        dataset: Option<String>,
+    },
+    Assign {
+        #[structopt(long, use_delimiter = true)]
+        players: Vec<String>,
+        #[structopt(long)]
+        dataset: Option<String>,
+    },
+    QuestStats {
+        #[structopt(long)]
+        dataset: Option<String>,
     },
-    Standings,
 }
 
 #[async_std::main]
@@ -39,18 +73,80 @@ async fn main() -> AllResult<()> {
         .await?;
     let opt = Command::from_args();
     match opt {
-        Command::Import { file } => {
+        Command::Import { file, dataset } => {
+            if let Some(dataset) = &dataset {
+                ensure_dataset(&pool, dataset.as_str()).await?;
+            }
             let f = std::fs::read_to_string(file)?;
             let games: Vec<types::GameInfo> = serde_yaml::from_str(f.as_str())?;
             for game in &games {
-                create_game(&pool, game).await?;
+                create_game(&pool, game, dataset.as_deref()).await?;
             }
         }
-        Command::Load { id } => {
-            dbg!(find_game(&pool, id.as_str()).await?);
+        Command::Load { id, dataset } => {
+            dbg!(find_game(&pool, id.as_str(), dataset.as_deref()).await?);
+        }
+        Command::Standings { dataset } => {
+            println!(
+                "{}",
+                types::standings(&load_all_games(&pool, dataset.as_deref()).await?)
+            );
+        }
+        Command::Ratings { dataset } => {
+            println!(
+                "{}",
+                types::ratings(&load_all_games(&pool, dataset.as_deref()).await?)
+            );
+        }
+        Command::Predict {
+            good,
+            evil,
+            dataset,
+        } => {
+            let ratings = types::ratings(&load_all_games(&pool, dataset.as_deref()).await?);
+            match types::predict(&ratings, &good, &evil) {
+                Some(p_good) => {
+                    println!("P(good wins) = {:.3}", p_good);
+                    println!("P(evil wins) = {:.3}", 1.0 - p_good);
+                }
+                None => println!("--good and --evil must each list at least one player"),
+            }
+        }
+        Command::Datasets => {
+            for dataset in list_datasets(&pool).await? {
+                println!("{}", dataset);
+            }
+        }
+        Command::RoleStats { dataset } => {
+            let by_role =
+                types::standings_by_role(&load_all_games(&pool, dataset.as_deref()).await?);
+            let mut roles: Vec<_> = by_role.keys().collect();
+            roles.sort_by_key(|role| format!("{:?}", role));
+            for role in roles {
+                let standing = &by_role[role];
+                println!(
+                    "{:?} (global win rate {:.2})",
+                    role,
+                    standing.total().win_percentage()
+                );
+                println!("{}", standing);
+            }
+        }
+        Command::Assign { players, dataset } => {
+            let ratings = types::ratings(&load_all_games(&pool, dataset.as_deref()).await?);
+            match types::assign(&ratings, &players) {
+                Some(assignment) => println!("{}", assignment),
+                None => println!(
+                    "no standard Avalon role distribution for {} players",
+                    players.len()
+                ),
+            }
         }
-        Command::Standings => {
-            println!("{}", types::standings(&load_all_games(&pool).await?));
+        Command::QuestStats { dataset } => {
+            println!(
+                "{}",
+                types::quest_stats(&load_all_games(&pool, dataset.as_deref()).await?)
+            );
         }
     };
     Ok(())
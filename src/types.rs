@@ -25,7 +25,7 @@ pub struct EndResult {
     pub victory_type: VictoryType,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, sqlx::Type)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, sqlx::Type)]
 #[sqlx(rename = "TEXT")]
 #[sqlx(rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
@@ -62,7 +62,7 @@ impl std::convert::TryFrom<&str> for Role {
     }
 }
 
-#[derive(Debug, Deserialize, sqlx::Type, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, sqlx::Type, PartialEq)]
 #[sqlx(rename = "TEXT")]
 #[sqlx(rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
@@ -71,6 +71,18 @@ pub enum QuestStatus {
     Fail,
 }
 
+impl std::convert::TryFrom<&str> for QuestStatus {
+    type Error = sqlx::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "success" => Ok(QuestStatus::Success),
+            "fail" => Ok(QuestStatus::Fail),
+            _ => Err(sqlx::Error::PoolClosed),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, sqlx::Type)]
 #[sqlx(rename = "TEXT")]
 #[sqlx(rename_all = "lowercase")]
@@ -80,13 +92,29 @@ pub enum Alignment {
     Evil,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum VictoryType {
     Assassination,
     Quest,
 }
 
+/// Reconstructs the `type` field the schema doesn't persist directly: evil only wins by
+/// assassination once good has already completed 3 quests (fewer than 3 real mission fails
+/// occurred) — otherwise evil won outright by failing 3 quests.
+pub fn infer_victory_type(winner: Alignment, quests: &[Quest]) -> VictoryType {
+    let num_failures = quests
+        .iter()
+        .filter(|q| q.status == QuestStatus::Fail)
+        .count();
+
+    if num_failures < 3 && winner == Alignment::Evil {
+        VictoryType::Assassination
+    } else {
+        VictoryType::Quest
+    }
+}
+
 impl GameInfo {
     pub fn winners(&self) -> Vec<&String> {
         let alignment = self.result.winner;
@@ -151,6 +179,23 @@ impl Default for Record {
     }
 }
 
+#[derive(Debug)]
+pub struct Dataset {
+    pub name: String,
+    pub last_import_at: i64,
+    pub game_count: i64,
+}
+
+impl fmt::Display for Dataset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<15} {:^6} games, last import at {}",
+            self.name, self.game_count, self.last_import_at
+        )
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct Standings<'a>(HashMap<&'a String, Record>);
 
@@ -185,6 +230,17 @@ impl<'a> fmt::Display for Standings<'a> {
     }
 }
 
+impl<'a> Standings<'a> {
+    /// The combined record across every player tracked in these standings.
+    pub fn total(&self) -> Record {
+        self.0.values().fold(Record::default(), |mut acc, record| {
+            acc.wins += record.wins;
+            acc.losses += record.losses;
+            acc
+        })
+    }
+}
+
 pub fn standings(info: &[GameInfo]) -> Standings {
     let mut standing = HashMap::new();
     for game in info {
@@ -224,6 +280,522 @@ pub fn standings_by_alignment(info: &[GameInfo]) -> HashMap<Alignment, Standings
     standings
 }
 
+pub fn standings_by_role(info: &[GameInfo]) -> HashMap<Role, Standings> {
+    let mut standings: HashMap<Role, Standings> = HashMap::new();
+    for game in info {
+        let winners = game.winners();
+        for (player, role) in &game.players {
+            let standing = standings.entry(*role).or_insert_with(Standings::default);
+            let record = standing.0.entry(player).or_insert_with(Record::default);
+            if winners.contains(&player) {
+                record.wins += 1;
+            } else {
+                record.losses += 1;
+            }
+        }
+    }
+    standings
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuestIndexRecord {
+    successes: u32,
+    fails: u32,
+    total_fail_votes: i32,
+}
+
+impl QuestIndexRecord {
+    pub fn success_rate(&self) -> f32 {
+        let total = self.successes + self.fails;
+        if total == 0 {
+            return 0.0;
+        }
+        self.successes as f32 / total as f32
+    }
+
+    pub fn average_fails(&self) -> f32 {
+        let total = self.successes + self.fails;
+        if total == 0 {
+            return 0.0;
+        }
+        self.total_fail_votes as f32 / total as f32
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PlayerQuestRecord {
+    participations: u32,
+    successes: u32,
+    failed_missions: u32,
+}
+
+impl PlayerQuestRecord {
+    pub fn success_rate(&self) -> f32 {
+        self.successes as f32 / self.participations as f32
+    }
+
+    pub fn failure_rate(&self) -> f32 {
+        self.failed_missions as f32 / self.participations as f32
+    }
+}
+
+const MAX_QUESTS_PER_GAME: usize = 5;
+
+#[derive(Debug, Default)]
+pub struct QuestStats<'a> {
+    per_quest: [QuestIndexRecord; MAX_QUESTS_PER_GAME],
+    per_player: HashMap<&'a String, PlayerQuestRecord>,
+}
+
+impl<'a> fmt::Display for QuestStats<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Quest Stats")?;
+        for (index, record) in self.per_quest.iter().enumerate() {
+            writeln!(
+                f,
+                "Quest {}: {:.2} success rate, {:.2} avg fails",
+                index + 1,
+                record.success_rate(),
+                record.average_fails()
+            )?;
+        }
+
+        writeln!(f, "---------")?;
+        writeln!(
+            f,
+            "{:^10} {:^8} {:^8} {:^8}",
+            "Name", "Played", "Win %", "Fail %"
+        )?;
+        let mut players: Vec<(&&'a String, &PlayerQuestRecord)> = self.per_player.iter().collect();
+        players.sort_by_key(|(name, _)| name.as_str());
+        for (name, record) in players {
+            writeln!(
+                f,
+                "{:<10} {:^8} {:^8.2} {:^8.2}",
+                name,
+                record.participations,
+                record.success_rate(),
+                record.failure_rate()
+            )?;
+        }
+
+        writeln!(f, "---------")
+    }
+}
+
+/// Aggregates per-quest-index success/fail rates and each player's personal mission
+/// record across `info`.
+pub fn quest_stats(info: &[GameInfo]) -> QuestStats {
+    let mut per_quest = [QuestIndexRecord::default(); MAX_QUESTS_PER_GAME];
+    let mut per_player: HashMap<&String, PlayerQuestRecord> = HashMap::new();
+
+    for game in info {
+        for (index, quest) in game.quests.iter().enumerate().take(MAX_QUESTS_PER_GAME) {
+            let record = &mut per_quest[index];
+            match quest.status {
+                QuestStatus::Success => record.successes += 1,
+                QuestStatus::Fail => record.fails += 1,
+            }
+            record.total_fail_votes += quest.fails.unwrap_or(0);
+
+            for participant in &quest.participants {
+                let player_record = per_player
+                    .entry(participant)
+                    .or_insert_with(PlayerQuestRecord::default);
+                player_record.participations += 1;
+                match quest.status {
+                    QuestStatus::Success => player_record.successes += 1,
+                    QuestStatus::Fail => player_record.failed_missions += 1,
+                }
+            }
+        }
+    }
+
+    QuestStats {
+        per_quest,
+        per_player,
+    }
+}
+
+const GLICKO_SCALE: f64 = 173.7178;
+const DEFAULT_RATING: f64 = 1500.0;
+const DEFAULT_RD: f64 = 350.0;
+const DEFAULT_VOLATILITY: f64 = 0.06;
+const SYSTEM_CONSTANT: f64 = 0.5;
+const VOLATILITY_CONVERGENCE: f64 = 0.000001;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rating {
+    pub r: f64,
+    pub rd: f64,
+    pub volatility: f64,
+}
+
+impl Rating {
+    /// A pessimistic single-number summary of a player's strength, used for ranking.
+    pub fn conservative(&self) -> f64 {
+        self.r - 2.0 * self.rd
+    }
+
+    fn mu(&self) -> f64 {
+        (self.r - DEFAULT_RATING) / GLICKO_SCALE
+    }
+
+    fn phi(&self) -> f64 {
+        self.rd / GLICKO_SCALE
+    }
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self {
+            r: DEFAULT_RATING,
+            rd: DEFAULT_RD,
+            volatility: DEFAULT_VOLATILITY,
+        }
+    }
+}
+
+fn glicko_g(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi * phi / (std::f64::consts::PI * std::f64::consts::PI)).sqrt()
+}
+
+fn glicko_e(mu: f64, mu_j: f64, phi_j: f64) -> f64 {
+    1.0 / (1.0 + (-glicko_g(phi_j) * (mu - mu_j)).exp())
+}
+
+/// Solves for the new volatility via the Illinois variant of regula falsi, as specified
+/// in the Glicko-2 paper.
+fn glicko_volatility(phi: f64, sigma: f64, v: f64, delta: f64) -> f64 {
+    let tau2 = SYSTEM_CONSTANT * SYSTEM_CONSTANT;
+    let a = (sigma * sigma).ln();
+    let f = |x: f64| {
+        let ex = x.exp();
+        (ex * (delta * delta - phi * phi - v - ex)) / (2.0 * (phi * phi + v + ex).powi(2))
+            - (x - a) / tau2
+    };
+
+    let mut big_a = a;
+    let mut big_b = if delta * delta > phi * phi + v {
+        (delta * delta - phi * phi - v).ln()
+    } else {
+        let mut k = 1.0;
+        while f(a - k * SYSTEM_CONSTANT) < 0.0 {
+            k += 1.0;
+        }
+        a - k * SYSTEM_CONSTANT
+    };
+
+    let mut f_a = f(big_a);
+    let mut f_b = f(big_b);
+    while (big_b - big_a).abs() > VOLATILITY_CONVERGENCE {
+        let c = big_a + (big_a - big_b) * f_a / (f_b - f_a);
+        let f_c = f(c);
+        if f_c * f_b <= 0.0 {
+            big_a = big_b;
+            f_a = f_b;
+        } else {
+            f_a /= 2.0;
+        }
+        big_b = c;
+        f_b = f_c;
+    }
+
+    (big_a / 2.0).exp()
+}
+
+/// Runs one Glicko-2 rating period for a player against `opponents`, each given as
+/// `(mu_j, phi_j, score)`. An empty `opponents` list is a period the player sat out,
+/// which only inflates their rating deviation.
+fn glicko_update(rating: &Rating, opponents: &[(f64, f64, f64)]) -> Rating {
+    let phi = rating.phi();
+
+    if opponents.is_empty() {
+        let phi_star = (phi * phi + rating.volatility * rating.volatility).sqrt();
+        return Rating {
+            r: rating.r,
+            rd: phi_star * GLICKO_SCALE,
+            volatility: rating.volatility,
+        };
+    }
+
+    let mu = rating.mu();
+
+    let v_inv: f64 = opponents
+        .iter()
+        .map(|(mu_j, phi_j, _)| {
+            let g = glicko_g(*phi_j);
+            let e = glicko_e(mu, *mu_j, *phi_j);
+            g * g * e * (1.0 - e)
+        })
+        .sum();
+    let v = 1.0 / v_inv;
+
+    let score_sum = |mu: f64| -> f64 {
+        opponents
+            .iter()
+            .map(|(mu_j, phi_j, s_j)| glicko_g(*phi_j) * (s_j - glicko_e(mu, *mu_j, *phi_j)))
+            .sum()
+    };
+
+    let delta = v * score_sum(mu);
+    let volatility = glicko_volatility(phi, rating.volatility, v, delta);
+
+    let phi_star = (phi * phi + volatility * volatility).sqrt();
+    let phi_new = 1.0 / (1.0 / (phi_star * phi_star) + 1.0 / v).sqrt();
+    let mu_new = mu + phi_new * phi_new * score_sum(mu);
+
+    Rating {
+        r: GLICKO_SCALE * mu_new + DEFAULT_RATING,
+        rd: GLICKO_SCALE * phi_new,
+        volatility,
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Ratings<'a>(HashMap<&'a String, Rating>);
+
+impl<'a> fmt::Display for Ratings<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Ratings")?;
+        writeln!(
+            f,
+            "{:^10} {:^8} {:^8} {:^8}",
+            "Name", "r", "RD", "Conservative"
+        )?;
+
+        let mut ratings: Vec<(&&'a String, &Rating)> = self.0.iter().collect();
+        ratings.sort_by(|(a_name, a), (b_name, b)| {
+            b.conservative()
+                .partial_cmp(&a.conservative())
+                .unwrap_or_else(|| a_name.cmp(b_name))
+        });
+        for (name, rating) in ratings {
+            writeln!(
+                f,
+                "{:<10} {:^8.1} {:^8.1} {:^8.1}",
+                name,
+                rating.r,
+                rating.rd,
+                rating.conservative()
+            )?;
+        }
+
+        writeln!(f, "---------")
+    }
+}
+
+impl<'a> Ratings<'a> {
+    pub fn get(&self, name: &str) -> Option<&Rating> {
+        self.0
+            .iter()
+            .find(|(k, _)| k.as_str() == name)
+            .map(|(_, v)| v)
+    }
+}
+
+/// Predicts the probability that Good wins a game between the given players, by
+/// treating each side as a single virtual opponent at the mean of its members' μ and φ.
+/// Returns `None` if either side is empty, since there's no virtual opponent to average.
+pub fn predict(ratings: &Ratings, good: &[String], evil: &[String]) -> Option<f64> {
+    if good.is_empty() || evil.is_empty() {
+        return None;
+    }
+
+    let mean = |names: &[String], f: fn(&Rating) -> f64| -> f64 {
+        names
+            .iter()
+            .map(|name| ratings.get(name).copied().unwrap_or_default())
+            .map(|rating| f(&rating))
+            .sum::<f64>()
+            / names.len() as f64
+    };
+
+    let good_mu = mean(good, Rating::mu);
+    let evil_mu = mean(evil, Rating::mu);
+    let evil_phi = mean(evil, Rating::phi);
+
+    Some(glicko_e(good_mu, evil_mu, evil_phi))
+}
+
+/// The standard Avalon Good/Evil split for a given player count.
+fn alignment_counts(player_count: usize) -> Option<(usize, usize)> {
+    match player_count {
+        5 => Some((3, 2)),
+        6 => Some((4, 2)),
+        7 => Some((4, 3)),
+        8 => Some((5, 3)),
+        9 => Some((6, 3)),
+        10 => Some((6, 4)),
+        _ => None,
+    }
+}
+
+/// The standard special-role loadout for a given player count.
+fn role_loadout(player_count: usize) -> Option<(Vec<Role>, Vec<Role>)> {
+    use Role::*;
+    match player_count {
+        5 => Some((vec![Merlin, Percival, Servant], vec![Assassin, Morgana])),
+        6 => Some((
+            vec![Merlin, Percival, Servant, Servant],
+            vec![Assassin, Morgana],
+        )),
+        7 => Some((
+            vec![Merlin, Percival, Servant, Servant],
+            vec![Assassin, Morgana, Oberon],
+        )),
+        8 => Some((
+            vec![Merlin, Percival, Servant, Servant, Servant],
+            vec![Assassin, Morgana, Oberon],
+        )),
+        9 => Some((
+            vec![Merlin, Percival, Servant, Servant, Servant, Servant],
+            vec![Assassin, Morgana, Oberon],
+        )),
+        10 => Some((
+            vec![Merlin, Percival, Servant, Servant, Servant, Servant],
+            vec![Assassin, Morgana, Oberon, Mordred],
+        )),
+        _ => None,
+    }
+}
+
+fn combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+    fn helper(
+        start: usize,
+        n: usize,
+        k: usize,
+        current: &mut Vec<usize>,
+        acc: &mut Vec<Vec<usize>>,
+    ) {
+        if current.len() == k {
+            acc.push(current.clone());
+            return;
+        }
+        for i in start..n {
+            current.push(i);
+            helper(i + 1, n, k, current, acc);
+            current.pop();
+        }
+    }
+    let mut acc = Vec::new();
+    helper(0, n, k, &mut Vec::new(), &mut acc);
+    acc
+}
+
+#[derive(Debug)]
+pub struct Assignment {
+    pub good: Vec<String>,
+    pub evil: Vec<String>,
+    pub good_roles: Vec<Role>,
+    pub evil_roles: Vec<Role>,
+    pub predicted_good_win_probability: f64,
+}
+
+impl fmt::Display for Assignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Good (P(good wins) = {:.2}):",
+            self.predicted_good_win_probability
+        )?;
+        for (player, role) in self.good.iter().zip(self.good_roles.iter()) {
+            writeln!(f, "  {:<10} {:?}", player, role)?;
+        }
+        writeln!(f, "Evil:")?;
+        for (player, role) in self.evil.iter().zip(self.evil_roles.iter()) {
+            writeln!(f, "  {:<10} {:?}", player, role)?;
+        }
+        Ok(())
+    }
+}
+
+/// Proposes the Good/Evil split (and special-role loadout) for `players` that minimizes
+/// predicted imbalance, scored via `predict` against the ratings derived from history.
+pub fn assign(ratings: &Ratings, players: &[String]) -> Option<Assignment> {
+    let n = players.len();
+    let (good_count, evil_count) = alignment_counts(n)?;
+    let (good_roles, evil_roles) = role_loadout(n)?;
+    debug_assert_eq!(good_roles.len(), good_count);
+    debug_assert_eq!(evil_roles.len(), evil_count);
+
+    let split = |combo: &[usize]| -> (Vec<String>, Vec<String>) {
+        let good = combo.iter().map(|&i| players[i].clone()).collect();
+        let evil = (0..n)
+            .filter(|i| !combo.contains(i))
+            .map(|i| players[i].clone())
+            .collect();
+        (good, evil)
+    };
+
+    let best_combo = combinations(n, good_count).into_iter().min_by(|a, b| {
+        let (good_a, evil_a) = split(a);
+        let (good_b, evil_b) = split(b);
+        let deviation_a =
+            (predict(ratings, &good_a, &evil_a).expect("good/evil split is non-empty") - 0.5).abs();
+        let deviation_b =
+            (predict(ratings, &good_b, &evil_b).expect("good/evil split is non-empty") - 0.5).abs();
+        deviation_a
+            .partial_cmp(&deviation_b)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    })?;
+
+    let (good, evil) = split(&best_combo);
+    let predicted_good_win_probability =
+        predict(ratings, &good, &evil).expect("good/evil split is non-empty");
+
+    Some(Assignment {
+        good,
+        evil,
+        good_roles,
+        evil_roles,
+        predicted_good_win_probability,
+    })
+}
+
+/// Computes a Glicko-2 rating for every player across `info`, treating each game as one
+/// rating period where a player's opponents are the opposing alignment.
+pub fn ratings(info: &[GameInfo]) -> Ratings {
+    let mut current: HashMap<&String, Rating> = HashMap::new();
+
+    for game in info {
+        let all_players = game.all_players();
+        for player in &all_players {
+            current.entry(player).or_insert_with(Rating::default);
+        }
+
+        let snapshot: HashMap<&String, Rating> = all_players
+            .iter()
+            .map(|player| (*player, current[*player]))
+            .collect();
+
+        let winner = game.result.winner;
+        let mut updates: HashMap<&String, Rating> = HashMap::new();
+        for player in &all_players {
+            let alignment = game.players[*player].alignment();
+            let opponents: Vec<(f64, f64, f64)> = all_players
+                .iter()
+                .filter(|opponent| game.players[**opponent].alignment() != alignment)
+                .map(|opponent| {
+                    let opponent_rating = snapshot[*opponent];
+                    let score = if alignment == winner { 1.0 } else { 0.0 };
+                    (opponent_rating.mu(), opponent_rating.phi(), score)
+                })
+                .collect();
+            updates.insert(*player, glicko_update(&snapshot[*player], &opponents));
+        }
+
+        for (player, rating) in current.iter_mut() {
+            if !all_players.contains(player) {
+                *rating = glicko_update(rating, &[]);
+            }
+        }
+        current.extend(updates);
+    }
+
+    Ratings(current)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -333,4 +905,127 @@ mod test {
             &Record { wins: 2, losses: 0 }
         );
     }
+
+    #[test]
+    fn test_glicko_update_matches_paper_example() {
+        // The worked example from Glickman's Glicko-2 paper: a player rated
+        // r=1500, RD=200, sigma=0.06 plays three opponents in one period and
+        // should land at r'=1464.06, RD'=151.52, sigma'=0.05999.
+        let rating = Rating {
+            r: 1500.0,
+            rd: 200.0,
+            volatility: 0.06,
+        };
+        let opponents = [
+            (
+                Rating {
+                    r: 1400.0,
+                    rd: 30.0,
+                    volatility: 0.06,
+                },
+                1.0,
+            ),
+            (
+                Rating {
+                    r: 1550.0,
+                    rd: 100.0,
+                    volatility: 0.06,
+                },
+                0.0,
+            ),
+            (
+                Rating {
+                    r: 1700.0,
+                    rd: 300.0,
+                    volatility: 0.06,
+                },
+                0.0,
+            ),
+        ];
+        let matches: Vec<(f64, f64, f64)> = opponents
+            .iter()
+            .map(|(opponent, score)| (opponent.mu(), opponent.phi(), *score))
+            .collect();
+
+        let updated = glicko_update(&rating, &matches);
+
+        assert!((updated.r - 1464.06).abs() < 0.01, "r = {}", updated.r);
+        assert!((updated.rd - 151.52).abs() < 0.01, "rd = {}", updated.rd);
+        assert!(
+            (updated.volatility - 0.05999).abs() < 0.0001,
+            "volatility = {}",
+            updated.volatility
+        );
+    }
+
+    #[test]
+    fn test_standings_by_role() {
+        let games: Vec<GameInfo> = serde_yaml::from_str(FILE).unwrap();
+        let by_role = standings_by_role(&games);
+        let merlin = by_role.get(&Role::Merlin).unwrap();
+        assert_eq!(
+            merlin.0.get(&String::from("player1")).unwrap(),
+            &Record { wins: 1, losses: 1 }
+        );
+    }
+
+    #[test]
+    fn test_assign_splits_players_by_standard_avalon_counts() {
+        let games: Vec<GameInfo> = serde_yaml::from_str(FILE).unwrap();
+        let ratings = ratings(&games);
+        let players: Vec<String> = vec!["player1", "player2", "player3", "player4", "player5"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let assignment = assign(&ratings, &players).unwrap();
+
+        assert_eq!(assignment.good.len(), 3);
+        assert_eq!(assignment.evil.len(), 2);
+        assert_eq!(assignment.good_roles.len(), 3);
+        assert_eq!(assignment.evil_roles.len(), 2);
+
+        let mut assigned: Vec<String> = assignment
+            .good
+            .iter()
+            .chain(assignment.evil.iter())
+            .cloned()
+            .collect();
+        assigned.sort();
+        let mut expected = players;
+        expected.sort();
+        assert_eq!(assigned, expected);
+
+        assert!((0.0..=1.0).contains(&assignment.predicted_good_win_probability));
+    }
+
+    #[test]
+    fn test_infer_victory_type_uses_real_quest_fail_counts() {
+        let fail = |votes| Quest {
+            status: QuestStatus::Fail,
+            fails: Some(votes),
+            participants: vec![],
+        };
+        let success = Quest {
+            status: QuestStatus::Success,
+            fails: Some(0),
+            participants: vec![],
+        };
+
+        // Evil already won by completing 3 quest fails, so this must not be
+        // misreported as an assassination. Had these quests all been read back
+        // with status `Success`, 0 fails would be counted here and
+        // `Assassination` returned instead.
+        let three_fails = vec![fail(1), fail(1), fail(1), success];
+        assert_eq!(
+            infer_victory_type(Alignment::Evil, &three_fails),
+            VictoryType::Quest
+        );
+
+        let two_fails = &three_fails[1..];
+        assert_eq!(
+            infer_victory_type(Alignment::Evil, two_fails),
+            VictoryType::Assassination
+        );
+    }
 }